@@ -59,122 +59,630 @@ fn matches(string_value: &str, expression: &str) -> Result<Vec<String>, Box<dyn
 	return Ok(result);
 }
 
-/// Increment build number in version string. (0.0.1 >> 0.0.2)
+/// The kind of version bump to apply, mirroring the bump enum used by
+/// workspace version managers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Bump {
+	Major,
+	Minor,
+	Patch,
+	PreMajor,
+	PreMinor,
+	PrePatch,
+	PreRelease,
+	Custom(String),
+}
+
+impl Bump {
+	/// Parse a bump kind from a CLI argument. (major/minor/patch/premajor/preminor/prepatch/prerelease/custom:#.#.#)
+	///
+	/// Returns an error for anything unrecognized, rather than silently
+	/// defaulting, so a mistyped or misplaced bump keyword is reported instead
+	/// of quietly performing a patch bump.
+	fn parse(arg: &str) -> Result<Bump, Box<dyn std::error::Error>> {
+		return match arg {
+			"major" => Ok(Bump::Major),
+			"minor" => Ok(Bump::Minor),
+			"patch" => Ok(Bump::Patch),
+			"premajor" => Ok(Bump::PreMajor),
+			"preminor" => Ok(Bump::PreMinor),
+			"prepatch" => Ok(Bump::PrePatch),
+			"prerelease" => Ok(Bump::PreRelease),
+			other => match other.strip_prefix("custom:") {
+				Some(custom) => Ok(Bump::Custom(custom.to_string())),
+				None => Err(format!("unrecognized bump kind: [{}]", arg).into()),
+			},
+		};
+	}
+}
+
+/// Bump (or append) a numeric pre-release identifier. (rc.1 >> rc.2, none >> 0)
+fn next_pre_release(pre: &Option<String>) -> String {
+	return match pre {
+		None => "0".to_string(),
+		Some(pre) => {
+			let mut parts: Vec<String> = pre.split('.').map(|s| s.to_string()).collect();
+			if let Some(last) = parts.last_mut() {
+				if let Ok(number) = last.parse::<u64>() {
+					*last = (number + 1).to_string();
+					return parts.join(".");
+				}
+			}
+			format!("{}.0", pre)
+		}
+	};
+}
+
+/// A parsed semantic version: `major.minor.patch[-pre-release][+build]`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Version {
+	major: u64,
+	minor: u64,
+	patch: u64,
+	pre: Option<String>,
+	build: Option<String>,
+}
+
+impl Version {
+	/// Parse a `Version` out of the first semver-shaped substring in `text`.
+	///
+	/// Tries the most specific shape first (pre-release + build metadata), then
+	/// falls back to narrower shapes, and finally a bare "#.#.#".
+	fn parse(text: &str) -> Result<Version, Box<dyn std::error::Error>> {
+		let result = matches(text, r#"(\d+)\.(\d+)\.(\d+)-([0-9A-Za-z.-]+)\+([0-9A-Za-z.-]+)"#)?;
+		if result.len() == 5 {
+			return Ok(Version {
+				major: result[0].parse::<u64>()?,
+				minor: result[1].parse::<u64>()?,
+				patch: result[2].parse::<u64>()?,
+				pre: Some(result[3].clone()),
+				build: Some(result[4].clone()),
+			});
+		}
+
+		let result = matches(text, r#"(\d+)\.(\d+)\.(\d+)-([0-9A-Za-z.-]+)"#)?;
+		if result.len() == 4 {
+			return Ok(Version {
+				major: result[0].parse::<u64>()?,
+				minor: result[1].parse::<u64>()?,
+				patch: result[2].parse::<u64>()?,
+				pre: Some(result[3].clone()),
+				build: None,
+			});
+		}
+
+		let result = matches(text, r#"(\d+)\.(\d+)\.(\d+)\+([0-9A-Za-z.-]+)"#)?;
+		if result.len() == 4 {
+			return Ok(Version {
+				major: result[0].parse::<u64>()?,
+				minor: result[1].parse::<u64>()?,
+				patch: result[2].parse::<u64>()?,
+				pre: None,
+				build: Some(result[3].clone()),
+			});
+		}
+
+		let result = matches(text, r#"(\d+)\.(\d+)\.(\d+)"#)?;
+		if result.len() == 3 {
+			return Ok(Version {
+				major: result[0].parse::<u64>()?,
+				minor: result[1].parse::<u64>()?,
+				patch: result[2].parse::<u64>()?,
+				pre: None,
+				build: None,
+			});
+		}
+
+		return Err(format!("not a version string: [{}]", text).into());
+	}
+
+	/// Apply a bump, returning the new `Version`.
+	///
+	/// Build metadata is always dropped, per semver precedence rules; pre-release
+	/// handling follows the bump kind.
+	fn bump(&self, kind: &Bump) -> Version {
+		let pre = self.pre.clone();
+
+		let (major, minor, patch, pre) = match kind {
+			Bump::Major => (self.major + 1, 0, 0, None),
+			Bump::Minor => (self.major, self.minor + 1, 0, None),
+			Bump::Patch => (self.major, self.minor, self.patch + 1, None),
+			Bump::PreMajor => (self.major + 1, 0, 0, Some(next_pre_release(&None))),
+			Bump::PreMinor => (self.major, self.minor + 1, 0, Some(next_pre_release(&None))),
+			Bump::PrePatch => (self.major, self.minor, self.patch + 1, Some(next_pre_release(&None))),
+			Bump::PreRelease => (self.major, self.minor, self.patch, Some(next_pre_release(&pre))),
+			Bump::Custom(_) => (self.major, self.minor, self.patch, pre),
+		};
+
+		return Version { major, minor, patch, pre, build: None };
+	}
+}
+
+impl std::fmt::Display for Version {
+	fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+		write!(f, "{}.{}.{}", self.major, self.minor, self.patch)?;
+		if let Some(pre) = &self.pre {
+			write!(f, "-{}", pre)?;
+		}
+		if let Some(build) = &self.build {
+			write!(f, "+{}", build)?;
+		}
+		return Ok(());
+	}
+}
+
+/// Bump a version string according to `kind`. (0.0.1 >> 0.0.2, 1.2.3 --major-> 2.0.0, ...)
+///
+/// Versions that don't parse as semver are passed through unchanged.
 ///
 /// # Arguments
-/// * `version` - Version string. (#.#.#)
-fn increment_build_number(version: &str) -> Result<String, Box<dyn std::error::Error>> {
-	let result = matches(version, r#"(\d+)\.(\d+)\.(\d+)"#)?;
-	if result.len() != 3 {
-		return Ok(version.to_string());
+/// * `version` - Version string. (#.#.#[-pre][+build])
+/// * `kind` - The kind of bump to apply.
+fn bump_version(version: &str, kind: &Bump) -> Result<String, Box<dyn std::error::Error>> {
+	if let Bump::Custom(custom) = kind {
+		return Ok(custom.clone());
 	}
 
-	let left = result[0].clone();
+	let parsed = match Version::parse(version) {
+		Ok(parsed) => parsed,
+		Err(_) => return Ok(version.to_string()),
+	};
 
-	let middle = result[1].clone();
+	return Ok(parsed.bump(kind).to_string());
+}
 
-	let right = result[2].clone();
-	let right = right.parse::<u32>()?;
-	let right = right + 1;
+/// The comparison operator prefixing a dependency version requirement.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RequirementOperator {
+	Exact,     // "="
+	Greater,   // ">"
+	GreaterEq, // ">="
+	Less,      // "<"
+	LessEq,    // "<="
+	Tilde,     // "~"
+	Caret,     // "^"
+	Bare,      // no operator prefix; Cargo treats this like "^"
+}
 
-	let result = format!("{}.{}.{}", left, middle, right);
+impl RequirementOperator {
+	/// The operator's textual prefix, as written in a manifest. ("" for `Bare`)
+	fn prefix(&self) -> &'static str {
+		return match self {
+			RequirementOperator::Exact => "=",
+			RequirementOperator::Greater => ">",
+			RequirementOperator::GreaterEq => ">=",
+			RequirementOperator::Less => "<",
+			RequirementOperator::LessEq => "<=",
+			RequirementOperator::Tilde => "~",
+			RequirementOperator::Caret => "^",
+			RequirementOperator::Bare => "",
+		};
+	}
+}
 
-	return Ok(result);
+/// A parsed Cargo dependency version requirement, e.g. `^1.2.3`, `~1.2`, `>=1.0`,
+/// `1.2` (bare), or a wildcard component like `1.*`.
+///
+/// Fields are kept as their original text rather than parsed integers, so a
+/// wildcard `*` component or a partial (major-only / major.minor) requirement
+/// round-trips exactly as written.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Requirement {
+	operator: RequirementOperator,
+	major: Option<String>,
+	minor: Option<String>,
+	patch: Option<String>,
 }
 
-/// Get version string in text. "#.#.#"
-fn read_version_string(line: &str) -> Result<String, Box<dyn std::error::Error>> {
-	// Check the line.
-	if !is_version_line(line) {
-		return Ok("".to_string());
-	}
+impl Requirement {
+	/// Parse a single version-requirement clause.
+	///
+	/// Each dot-separated field after the operator prefix must be a bare
+	/// non-negative integer or a `*` wildcard; anything else is rejected so a
+	/// caller can't write a garbage requirement into a manifest.
+	fn parse(text: &str) -> Result<Requirement, Box<dyn std::error::Error>> {
+		let trimmed = text.trim();
+
+		let (operator, rest) = if let Some(rest) = trimmed.strip_prefix(">=") {
+			(RequirementOperator::GreaterEq, rest)
+		} else if let Some(rest) = trimmed.strip_prefix("<=") {
+			(RequirementOperator::LessEq, rest)
+		} else if let Some(rest) = trimmed.strip_prefix('=') {
+			(RequirementOperator::Exact, rest)
+		} else if let Some(rest) = trimmed.strip_prefix('>') {
+			(RequirementOperator::Greater, rest)
+		} else if let Some(rest) = trimmed.strip_prefix('<') {
+			(RequirementOperator::Less, rest)
+		} else if let Some(rest) = trimmed.strip_prefix('~') {
+			(RequirementOperator::Tilde, rest)
+		} else if let Some(rest) = trimmed.strip_prefix('^') {
+			(RequirementOperator::Caret, rest)
+		} else {
+			(RequirementOperator::Bare, trimmed)
+		};
+
+		let fields: Vec<&str> = rest.trim().split('.').collect();
+		if fields.len() > 3 {
+			return Err(format!("invalid version requirement: [{}]", text).into());
+		}
+		for field in fields.iter() {
+			if *field != "*" && field.parse::<u64>().is_err() {
+				return Err(format!("invalid version requirement: [{}]", text).into());
+			}
+		}
 
-	// Matching the version string.
-	let result = matches(line, r#"\s*version\s*=\s*"(.*)""#)?;
-	if result.len() != 1 {
-		return Ok("".to_string());
+		return Ok(Requirement { operator, major: fields.get(0).map(|s| s.to_string()), minor: fields.get(1).map(|s| s.to_string()), patch: fields.get(2).map(|s| s.to_string()) });
 	}
-	let version_string = result[0].clone();
-	if version_string == "" {
-		return Ok("".to_string());
+}
+
+impl std::fmt::Display for Requirement {
+	fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+		write!(f, "{}", self.operator.prefix())?;
+
+		let fields: Vec<&str> = [&self.major, &self.minor, &self.patch].into_iter().flatten().map(|s| s.as_str()).collect();
+		if fields.is_empty() {
+			return write!(f, "*");
+		}
+
+		return write!(f, "{}", fields.join("."));
 	}
+}
 
-	return Ok(version_string);
+/// The version field a template placeholder refers to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TemplateField {
+	Major,
+	Minor,
+	Patch,
+	Pre,
+	Version,
 }
 
-/// Check if the line is a version line.
-fn is_version_line(line: &str) -> bool {
-	return line.trim().starts_with("version");
+impl TemplateField {
+	/// Parse a placeholder's inner name. (major/minor/patch/pre/version)
+	fn parse(name: &str) -> Result<TemplateField, Box<dyn std::error::Error>> {
+		return match name {
+			"major" => Ok(TemplateField::Major),
+			"minor" => Ok(TemplateField::Minor),
+			"patch" => Ok(TemplateField::Patch),
+			"pre" => Ok(TemplateField::Pre),
+			"version" => Ok(TemplateField::Version),
+			other => Err(format!("unknown template placeholder: [{{{}}}]", other).into()),
+		};
+	}
+
+	/// Render this field against a `Version`.
+	fn render(&self, version: &Version) -> String {
+		return match self {
+			TemplateField::Major => version.major.to_string(),
+			TemplateField::Minor => version.minor.to_string(),
+			TemplateField::Patch => version.patch.to_string(),
+			TemplateField::Pre => version.pre.clone().unwrap_or_default(),
+			TemplateField::Version => version.to_string(),
+		};
+	}
 }
 
-/// Convert string to quoted string.
-fn quoted(s: &str) -> String {
-	return format!("\"{}\"", s);
+/// One token of a tokenized version template: either literal text or a
+/// `{field}` placeholder.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum TemplateToken {
+	Literal(String),
+	Placeholder(TemplateField),
 }
 
-/// Carefully replace version string in text.
-///
-/// # Arguments
-/// * `line` - Line text.
-/// * `version` - Version string. (#.#.#)
-/// * `new_version` - New version string. (#.#.#)
-fn replace_string_carefully(line: &str, version: &str, new_version: &str) -> Result<String, Box<dyn std::error::Error>> {
-	let placeholder = quoted(&version);
-	let new_version = quoted(&new_version);
-	let result_string = line.replace(&placeholder, &new_version);
-	return Ok(result_string);
+/// A tokenized version-string template, e.g. `"v{major}.{minor}.{patch}"` or
+/// `"#define VERSION \"{version}\""`, for emitting the bumped version into
+/// non-Cargo files or commands.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Template {
+	tokens: Vec<TemplateToken>,
 }
 
-/// Convert version string.
-fn update_version_string_if_needed(line: &str, new_version: &str) -> Result<String, Box<dyn std::error::Error>> {
-	// Detect version "#.#.#" string.
-	let version = read_version_string(line)?;
-	if version == "" {
-		// No version string.
-		return Ok(line.to_string());
+impl Template {
+	/// Tokenize a template into literal and placeholder tokens.
+	fn parse(text: &str) -> Result<Template, Box<dyn std::error::Error>> {
+		let mut tokens: Vec<TemplateToken> = vec![];
+		let mut literal = String::new();
+		let mut chars = text.chars();
+
+		while let Some(c) = chars.next() {
+			if c != '{' {
+				literal.push(c);
+				continue;
+			}
+
+			if !literal.is_empty() {
+				tokens.push(TemplateToken::Literal(literal.clone()));
+				literal.clear();
+			}
+
+			let mut name = String::new();
+			let mut closed = false;
+			for c in chars.by_ref() {
+				if c == '}' {
+					closed = true;
+					break;
+				}
+				name.push(c);
+			}
+			if !closed {
+				return Err(format!("unterminated placeholder in template: [{}]", text).into());
+			}
+
+			tokens.push(TemplateToken::Placeholder(TemplateField::parse(&name)?));
+		}
+
+		if !literal.is_empty() {
+			tokens.push(TemplateToken::Literal(literal));
+		}
+
+		return Ok(Template { tokens });
 	}
 
-	// Replace version number carefully.
-	let converted_line = replace_string_carefully(line, &version, &new_version)?;
+	/// Render the template against a `Version`, substituting each placeholder
+	/// with its corresponding component.
+	fn render(&self, version: &Version) -> String {
+		let mut result = String::new();
 
-	info!("AFFECTED LINE:\n        SRC [{}]\n        NEW [{}]", line, &converted_line);
+		for token in self.tokens.iter() {
+			match token {
+				TemplateToken::Literal(text) => result.push_str(text),
+				TemplateToken::Placeholder(field) => result.push_str(&field.render(version)),
+			}
+		}
 
-	return Ok(converted_line);
+		return result;
+	}
 }
 
-/// Detect version from file.
-fn detect_version_from_file(path: &str) -> Result<String, Box<dyn std::error::Error>> {
-	// Read file content.
+/// Read `[package].version` from a Cargo.toml manifest, via a proper TOML parser
+/// rather than a line match, so it can't be fooled by a `version` key nested
+/// under `[dependencies.foo]` or similar.
+fn read_cargo_toml_version(path: &str) -> Result<String, Box<dyn std::error::Error>> {
 	let text = std::fs::read_to_string(path)?;
+	let document = text.parse::<toml_edit::Document>()?;
+
+	let version = document
+		.get("package")
+		.and_then(|package| package.get("version"))
+		.and_then(|version| version.as_str())
+		.ok_or_else(|| -> Box<dyn std::error::Error> { format!("[package].version not found in [{}]", path).into() })?;
+
+	return Ok(version.to_string());
+}
+
+/// Bump `[package].version` in a Cargo.toml manifest, via a format-preserving
+/// TOML editor so the rest of the file's formatting and comments survive.
+fn update_cargo_toml_version(path: &str, new_version: &str) -> Result<(), Box<dyn std::error::Error>> {
+	let text = std::fs::read_to_string(path)?;
+	let mut document = text.parse::<toml_edit::Document>()?;
+
+	document["package"]["version"] = toml_edit::value(new_version);
 
-	// Convert version line.
-	let lines = text.lines();
-	for line in lines {
-		let version = read_version_string(line)?;
-		if version != "" {
-			return Ok(version);
+	std::fs::write(path, document.to_string())?;
+
+	return Ok(());
+}
+
+/// A Cargo workspace member: a crate name plus the path to its manifest.
+struct Member {
+	name: String,
+	manifest_path: String,
+}
+
+/// Read `[package].name` from a Cargo.toml manifest.
+fn read_cargo_toml_name(path: &str) -> Result<String, Box<dyn std::error::Error>> {
+	let text = std::fs::read_to_string(path)?;
+	let document = text.parse::<toml_edit::Document>()?;
+
+	let name = document
+		.get("package")
+		.and_then(|package| package.get("name"))
+		.and_then(|name| name.as_str())
+		.ok_or_else(|| -> Box<dyn std::error::Error> { format!("[package].name not found in [{}]", path).into() })?;
+
+	return Ok(name.to_string());
+}
+
+/// Check whether a Cargo.toml manifest declares a `[workspace]` table.
+fn is_workspace_manifest(document: &toml_edit::Document) -> bool {
+	return document.get("workspace").is_some();
+}
+
+/// Read `[workspace.package].version`, if the manifest declares one.
+fn read_workspace_package_version(document: &toml_edit::Document) -> Option<String> {
+	return document.get("workspace")?.get("package")?.get("version")?.as_str().map(|s| s.to_string());
+}
+
+/// Bump `[workspace.package].version` in the root Cargo.toml manifest.
+fn update_workspace_package_version(path: &str, new_version: &str) -> Result<(), Box<dyn std::error::Error>> {
+	let text = std::fs::read_to_string(path)?;
+	let mut document = text.parse::<toml_edit::Document>()?;
+
+	document["workspace"]["package"]["version"] = toml_edit::value(new_version);
+
+	std::fs::write(path, document.to_string())?;
+
+	return Ok(());
+}
+
+/// Expand `[workspace].members` globs (relative to `workspace_dir`) into member manifests.
+fn workspace_members(workspace_dir: &str, document: &toml_edit::Document) -> Result<Vec<Member>, Box<dyn std::error::Error>> {
+	let mut members: Vec<Member> = vec![];
+	let mut seen_manifest_paths: std::collections::HashSet<String> = std::collections::HashSet::new();
+
+	let patterns = match document.get("workspace").and_then(|workspace| workspace.get("members")).and_then(|members| members.as_array()) {
+		Some(patterns) => patterns,
+		None => return Ok(members),
+	};
+
+	for pattern in patterns.iter() {
+		let pattern = match pattern.as_str() {
+			Some(pattern) => pattern,
+			None => continue,
+		};
+
+		let full_pattern = format!("{}/{}/Cargo.toml", workspace_dir, pattern);
+		for entry in glob::glob(&full_pattern)? {
+			let manifest_path = entry?.to_string_lossy().to_string();
+			if !seen_manifest_paths.insert(manifest_path.clone()) {
+				// Already matched by an earlier (overlapping) members glob.
+				continue;
+			}
+			let name = read_cargo_toml_name(&manifest_path)?;
+			members.push(Member { name, manifest_path });
 		}
 	}
 
-	return Ok("".to_string());
+	return Ok(members);
 }
 
-/// Increment cargo version.
-fn update_cargo_version(path: &str, version: &str) -> Result<(), Box<dyn std::error::Error>> {
-	// Read file content.
+/// Dependency tables that may carry workspace-internal (path) dependencies.
+const DEPENDENCY_TABLES: [&str; 3] = ["dependencies", "dev-dependencies", "build-dependencies"];
+
+/// Update the `version` field of path-based (workspace-internal) dependencies
+/// in `manifest_path` that reference one of the bumped crates, so intra-workspace
+/// requirements stay consistent with the bump.
+fn update_internal_dependency_versions(manifest_path: &str, bumped: &std::collections::HashMap<String, String>) -> Result<(), Box<dyn std::error::Error>> {
+	let text = std::fs::read_to_string(manifest_path)?;
+	let mut document = text.parse::<toml_edit::Document>()?;
+	let mut changed = false;
+
+	for table_name in DEPENDENCY_TABLES.iter() {
+		let table = match document.get_mut(table_name).and_then(|item| item.as_table_like_mut()) {
+			Some(table) => table,
+			None => continue,
+		};
+
+		for (name, new_version) in bumped.iter() {
+			let dependency = match table.get_mut(name) {
+				Some(dependency) => dependency,
+				None => continue,
+			};
+
+			let dependency = match dependency.as_table_like_mut() {
+				Some(dependency) => dependency,
+				None => continue,
+			};
+
+			if dependency.get("path").is_none() {
+				// Not a workspace-internal dependency.
+				continue;
+			}
+
+			if dependency.contains_key("version") {
+				dependency.insert("version", toml_edit::value(new_version.as_str()));
+				changed = true;
+			}
+		}
+	}
+
+	if changed {
+		std::fs::write(manifest_path, document.to_string())?;
+	}
+
+	return Ok(());
+}
+
+/// Bump matching `[[package]]` entries in Cargo.lock by crate name, so a
+/// workspace's several independently-bumped crates each land their own version.
+fn update_cargo_lock_versions(path: &str, bumped: &std::collections::HashMap<String, String>) -> Result<(), Box<dyn std::error::Error>> {
 	let text = std::fs::read_to_string(path)?;
+	let mut document = text.parse::<toml_edit::Document>()?;
 
-	// Convert version line.
-	let lines = text.lines();
-	let mut result_lines: Vec<String> = vec![];
-	for line in lines {
-		let line = update_version_string_if_needed(line, version)?;
-		result_lines.push(line);
+	let packages = match document.get_mut("package").and_then(|item| item.as_array_of_tables_mut()) {
+		Some(packages) => packages,
+		None => return Ok(()),
+	};
+
+	let mut changed = false;
+	for package in packages.iter_mut() {
+		let name = match package.get("name").and_then(|item| item.as_str()) {
+			Some(name) => name.to_string(),
+			None => continue,
+		};
+		if let Some(new_version) = bumped.get(&name) {
+			package.insert("version", toml_edit::value(new_version.as_str()));
+			changed = true;
+		}
 	}
-	let content = result_lines.join("\n") + "\n";
 
-	// Write file content.
-	std::fs::write(path, content)?;
+	if changed {
+		std::fs::write(path, document.to_string())?;
+	}
+
+	return Ok(());
+}
+
+/// Rewrite a named dependency's version requirement in a manifest's dependency
+/// tables to `new_requirement`. The requirement is parsed through `Requirement`
+/// first (rejecting anything that isn't a valid requirement clause) and then
+/// re-emitted via its `Display` impl, so the operator semantics are preserved
+/// rather than whatever raw text happened to be passed in. Opt-in: normal bump
+/// runs don't touch dependency requirements.
+fn set_dependency_requirement(manifest_path: &str, name: &str, new_requirement: &str) -> Result<(), Box<dyn std::error::Error>> {
+	let new_requirement = Requirement::parse(new_requirement)?.to_string();
+
+	let text = std::fs::read_to_string(manifest_path)?;
+	let mut document = text.parse::<toml_edit::Document>()?;
+	let mut changed = false;
+
+	for table_name in DEPENDENCY_TABLES.iter() {
+		let table = match document.get_mut(table_name).and_then(|item| item.as_table_like_mut()) {
+			Some(table) => table,
+			None => continue,
+		};
+
+		let dependency = match table.get_mut(name) {
+			Some(dependency) => dependency,
+			None => continue,
+		};
+
+		if dependency.is_str() {
+			// Bare `foo = "1.2.3"` form.
+			*dependency = toml_edit::value(new_requirement.as_str());
+			changed = true;
+			continue;
+		}
+
+		if let Some(dependency_table) = dependency.as_table_like_mut() {
+			// Table form: `foo = { version = "1.2.3", ... }`.
+			if dependency_table.contains_key("version") {
+				dependency_table.insert("version", toml_edit::value(new_requirement.as_str()));
+				changed = true;
+			}
+		}
+	}
+
+	if changed {
+		std::fs::write(manifest_path, document.to_string())?;
+	}
+
+	return Ok(());
+}
+
+/// Emit rendered `--template` line(s) to the requested output target.
+///
+/// With `--template-output <path>`, writes one line per entry to that file
+/// (truncating any existing content) so a script can read exactly the
+/// rendered version(s) and nothing else. Without it, prints each line via
+/// `info!` as before.
+fn emit_template_output(output: &Option<String>, lines: &[String]) -> Result<(), Box<dyn std::error::Error>> {
+	match output {
+		Some(path) => {
+			let mut contents = lines.join("\n");
+			if !lines.is_empty() {
+				contents.push('\n');
+			}
+			std::fs::write(path, contents)?;
+		}
+		None => {
+			for line in lines.iter() {
+				info!("{}", line);
+			}
+		}
+	}
 
 	return Ok(());
 }
@@ -182,21 +690,215 @@ fn update_cargo_version(path: &str, version: &str) -> Result<(), Box<dyn std::er
 struct Application;
 
 impl Application {
+	/// Determine the requested bump kind from CLI arguments.
+	///
+	/// The bump keyword is positional, but flags (`--only`, `--set-dependency`,
+	/// `--template`, `--template-output`) each consume the argument right after them, so the bump
+	/// keyword isn't necessarily at position 0 — it's whatever positional
+	/// argument is left once every flag and its value are skipped. Errors
+	/// (rather than silently defaulting) if no positional argument remains, or
+	/// if it isn't a recognized bump kind.
+	fn bump_from_args(&self) -> Result<Bump, Box<dyn std::error::Error>> {
+		const FLAGS_WITH_VALUE: &[&str] = &["--only", "--set-dependency", "--template", "--template-output"];
+
+		let args: Vec<String> = std::env::args().skip(1).collect();
+
+		let mut index = 0;
+		while index < args.len() {
+			let arg = &args[index];
+			if FLAGS_WITH_VALUE.contains(&arg.as_str()) {
+				// Skip the flag and the value that belongs to it.
+				index += 2;
+				continue;
+			}
+			return Bump::parse(arg);
+		}
+
+		return Err("no bump kind given (expected e.g. major/minor/patch)".into());
+	}
+
+	/// Parse an optional `--only name1,name2` filter restricting which workspace
+	/// members get bumped. Returns `None` when absent, meaning "bump everything".
+	fn only_from_args(&self) -> Option<Vec<String>> {
+		let args: Vec<String> = std::env::args().skip(1).collect();
+		for (index, arg) in args.iter().enumerate() {
+			if arg == "--only" {
+				let names = args.get(index + 1)?;
+				return Some(names.split(',').map(|s| s.to_string()).collect());
+			}
+		}
+		return None;
+	}
+
+	/// Parse an optional `--set-dependency name=requirement` flag for rewriting
+	/// a single dependency's version requirement. Opt-in; absent by default.
+	fn set_dependency_from_args(&self) -> Option<(String, String)> {
+		let args: Vec<String> = std::env::args().skip(1).collect();
+		for (index, arg) in args.iter().enumerate() {
+			if arg == "--set-dependency" {
+				let pair = args.get(index + 1)?;
+				let (name, requirement) = pair.split_once('=')?;
+				return Some((name.to_string(), requirement.to_string()));
+			}
+		}
+		return None;
+	}
+
+	/// Parse an optional `--template "<template>"` flag for rendering the bumped
+	/// version through a custom template (e.g. a header-file line or tag name).
+	fn template_from_args(&self) -> Option<String> {
+		let args: Vec<String> = std::env::args().skip(1).collect();
+		for (index, arg) in args.iter().enumerate() {
+			if arg == "--template" {
+				return args.get(index + 1).cloned();
+			}
+		}
+		return None;
+	}
+
+	/// Parse an optional `--template-output <path>` flag naming a file the
+	/// rendered `--template` line(s) should be written to, instead of printed
+	/// to stdout. Lets a script consume the rendered version without having to
+	/// pick it out of the `[INFO]`/`[ERROR]` log lines.
+	fn template_output_from_args(&self) -> Option<String> {
+		let args: Vec<String> = std::env::args().skip(1).collect();
+		for (index, arg) in args.iter().enumerate() {
+			if arg == "--template-output" {
+				return args.get(index + 1).cloned();
+			}
+		}
+		return None;
+	}
+
 	pub fn run(&self) -> Result<(), Box<dyn std::error::Error>> {
-		// Detect version from Cargo.toml.
-		let version = detect_version_from_file("Cargo.toml")?;
+		// Determine the bump kind. (major/minor/patch/pre-release/custom)
+		let bump = self.bump_from_args()?;
+
+		let text = std::fs::read_to_string("Cargo.toml")?;
+		let document = text.parse::<toml_edit::Document>()?;
+
+		// Manifests that `--set-dependency` may rewrite: the root, plus every
+		// workspace member when this is a workspace.
+		let mut manifests_for_dependency_edits: Vec<String> = vec!["Cargo.toml".to_string()];
+
+		let template_output = self.template_output_from_args();
+
+		if is_workspace_manifest(&document) {
+			let only = self.only_from_args();
+			let template = self.template_from_args();
+			let (members, rendered) = self.run_workspace(&bump, &only, &template, &document)?;
+			emit_template_output(&template_output, &rendered)?;
+			manifests_for_dependency_edits.extend(members.into_iter().map(|member| member.manifest_path));
+		} else {
+			// Detect package name + version from Cargo.toml. ([package].name / [package].version)
+			let name = read_cargo_toml_name("Cargo.toml")?;
+			let version = read_cargo_toml_version("Cargo.toml")?;
 
-		// Increment build number. (3rd field)
-		let new_version = increment_build_number(&version)?;
+			// Bump version according to the selected bump kind.
+			let new_version = bump_version(&version, &bump)?;
 
-		// Update version in Cargo.toml.
-		update_cargo_version("Cargo.toml", &new_version)?;
+			// Update version in Cargo.toml.
+			update_cargo_toml_version("Cargo.toml", &new_version)?;
 
-		// Update version in Cargo.lock.
-		update_cargo_version("Cargo.lock", &new_version)?;
+			// Update version in Cargo.lock. ([[package]] entry matching this crate's name only)
+			let mut bumped: std::collections::HashMap<String, String> = std::collections::HashMap::new();
+			bumped.insert(name, new_version.clone());
+			update_cargo_lock_versions("Cargo.lock", &bumped)?;
+
+			// Opt-in: render the bumped version through a custom template.
+			if let Some(template) = self.template_from_args() {
+				let version = Version::parse(&new_version)?;
+				let rendered = Template::parse(&template)?.render(&version);
+				emit_template_output(&template_output, &[rendered])?;
+			}
+		}
+
+		// Opt-in: rewrite a single dependency's version requirement, wherever it's declared.
+		if let Some((name, requirement)) = self.set_dependency_from_args() {
+			for manifest_path in manifests_for_dependency_edits.iter() {
+				set_dependency_requirement(manifest_path, &name, &requirement)?;
+			}
+		}
 
 		return Ok(());
 	}
+
+	/// Bump every (or `only`-selected) workspace member's `[package].version`, the
+	/// root `[workspace.package].version` if present, and any internal path
+	/// dependency requirements that point at a bumped crate. Returns the full
+	/// member list (regardless of `only`) so callers can address every manifest,
+	/// along with the rendered `--template` line for each bumped member (empty
+	/// when no template was given).
+	fn run_workspace(&self, bump: &Bump, only: &Option<Vec<String>>, template: &Option<String>, document: &toml_edit::Document) -> Result<(Vec<Member>, Vec<String>), Box<dyn std::error::Error>> {
+		let members = workspace_members(".", document)?;
+
+		let mut bumped: std::collections::HashMap<String, String> = std::collections::HashMap::new();
+
+		for member in members.iter() {
+			if let Some(only) = only {
+				if !only.contains(&member.name) {
+					continue;
+				}
+			}
+
+			let version = read_cargo_toml_version(&member.manifest_path)?;
+			let new_version = bump_version(&version, bump)?;
+			update_cargo_toml_version(&member.manifest_path, &new_version)?;
+
+			bumped.insert(member.name.clone(), new_version);
+		}
+
+		// `[workspace.package].version` is shared, not tied to any one member
+		// name, so `--only` restricts it the same way it restricts members:
+		// leave it untouched whenever a filter is in effect.
+		if only.is_none() {
+			if let Some(version) = read_workspace_package_version(document) {
+				let new_version = bump_version(&version, bump)?;
+				update_workspace_package_version("Cargo.toml", &new_version)?;
+			}
+		}
+
+		// A root manifest can declare both `[package]` and `[workspace]` (a
+		// normal hybrid layout); its own package version is never one of
+		// `workspace.members`, so bump it here, subject to the same `--only`
+		// filter as every other member.
+		if let Ok(root_name) = read_cargo_toml_name("Cargo.toml") {
+			let skip = match only {
+				Some(only) => !only.contains(&root_name),
+				None => false,
+			};
+			if !skip {
+				let version = read_cargo_toml_version("Cargo.toml")?;
+				let new_version = bump_version(&version, bump)?;
+				update_cargo_toml_version("Cargo.toml", &new_version)?;
+
+				bumped.insert(root_name, new_version);
+			}
+		}
+
+		// Keep intra-workspace path dependencies pointed at the new versions.
+		update_internal_dependency_versions("Cargo.toml", &bumped)?;
+		for member in members.iter() {
+			update_internal_dependency_versions(&member.manifest_path, &bumped)?;
+		}
+
+		// Update Cargo.lock. ([[package]] entries, matched by name)
+		update_cargo_lock_versions("Cargo.lock", &bumped)?;
+
+		// Opt-in: render each bumped member's new version through a custom template.
+		let mut rendered: Vec<String> = vec![];
+		if let Some(template) = template {
+			let template = Template::parse(template)?;
+			for member in members.iter() {
+				if let Some(new_version) = bumped.get(&member.name) {
+					let version = Version::parse(new_version)?;
+					rendered.push(format!("{}: {}", member.name, template.render(&version)));
+				}
+			}
+		}
+
+		return Ok((members, rendered));
+	}
 }
 
 fn main() {
@@ -212,54 +914,359 @@ fn main() {
 mod tests {
 	use super::*;
 
+	/// A scratch file path under the system temp dir, unique to this test process and `name`.
+	fn temp_path(name: &str) -> std::path::PathBuf {
+		let mut path = std::env::temp_dir();
+		path.push(format!("r-increment-cargo-version-test-{}-{}", std::process::id(), name));
+		return path;
+	}
+
+	#[test]
+	fn test_read_and_update_cargo_toml_version_leaves_unrelated_version_keys_alone() {
+		let path = temp_path("read-update-cargo-toml-version.toml");
+		std::fs::write(&path, "[package]\nname = \"demo\"\nversion = \"0.1.0\"\n\n[dependencies.foo]\nversion = \"9.9.9\"\n").unwrap();
+		let path_str = path.to_str().unwrap();
+
+		let version = read_cargo_toml_version(path_str).unwrap();
+		assert_eq!(version, "0.1.0");
+
+		update_cargo_toml_version(path_str, "0.2.0").unwrap();
+
+		assert_eq!(read_cargo_toml_version(path_str).unwrap(), "0.2.0");
+
+		let text = std::fs::read_to_string(path_str).unwrap();
+		let document = text.parse::<toml_edit::Document>().unwrap();
+		assert_eq!(document["dependencies"]["foo"]["version"].as_str().unwrap(), "9.9.9");
+
+		std::fs::remove_file(path).unwrap();
+	}
+
+	#[test]
+	fn test_read_cargo_toml_name_and_version_error_instead_of_panicking_without_package_table() {
+		let path = temp_path("read-cargo-toml-no-package-table.toml");
+		std::fs::write(&path, "[workspace]\nmembers = [\"crates/*\"]\n").unwrap();
+		let path_str = path.to_str().unwrap();
+
+		assert!(read_cargo_toml_name(path_str).is_err());
+		assert!(read_cargo_toml_version(path_str).is_err());
+
+		std::fs::remove_file(path).unwrap();
+	}
+
+	#[test]
+	fn test_update_cargo_lock_versions_only_touches_named_package() {
+		let path = temp_path("update-cargo-lock-versions.lock");
+		std::fs::write(
+			&path,
+			concat!(
+				"version = 3\n",
+				"\n",
+				"[[package]]\n",
+				"name = \"demo\"\n",
+				"version = \"0.1.0\"\n",
+				"\n",
+				"[[package]]\n",
+				"name = \"some-dep\"\n",
+				"version = \"2.5.1\"\n",
+				"\n",
+				"[[package]]\n",
+				"name = \"other-dep\"\n",
+				"version = \"9.9.9\"\n",
+			),
+		)
+		.unwrap();
+		let path_str = path.to_str().unwrap();
+
+		let mut bumped: std::collections::HashMap<String, String> = std::collections::HashMap::new();
+		bumped.insert("demo".to_string(), "0.1.1".to_string());
+		update_cargo_lock_versions(path_str, &bumped).unwrap();
+
+		let text = std::fs::read_to_string(path_str).unwrap();
+		let document = text.parse::<toml_edit::Document>().unwrap();
+		let packages = document["package"].as_array_of_tables().unwrap();
+
+		let mut versions: std::collections::HashMap<String, String> = std::collections::HashMap::new();
+		for package in packages.iter() {
+			versions.insert(package["name"].as_str().unwrap().to_string(), package["version"].as_str().unwrap().to_string());
+		}
+
+		assert_eq!(versions.get("demo").unwrap(), "0.1.1");
+		assert_eq!(versions.get("some-dep").unwrap(), "2.5.1");
+		assert_eq!(versions.get("other-dep").unwrap(), "9.9.9");
+
+		std::fs::remove_file(path).unwrap();
+	}
+
+	#[test]
+	fn test_workspace_members_expands_globs() {
+		let root = temp_path("workspace-members");
+		std::fs::create_dir_all(root.join("crates/a")).unwrap();
+		std::fs::create_dir_all(root.join("crates/b")).unwrap();
+
+		std::fs::write(root.join("Cargo.toml"), "[workspace]\nmembers = [\"crates/*\"]\n").unwrap();
+		std::fs::write(root.join("crates/a/Cargo.toml"), "[package]\nname = \"crate-a\"\nversion = \"0.1.0\"\n").unwrap();
+		std::fs::write(root.join("crates/b/Cargo.toml"), "[package]\nname = \"crate-b\"\nversion = \"0.2.0\"\n").unwrap();
+
+		let root_text = std::fs::read_to_string(root.join("Cargo.toml")).unwrap();
+		let document = root_text.parse::<toml_edit::Document>().unwrap();
+
+		let mut members = workspace_members(root.to_str().unwrap(), &document).unwrap();
+		members.sort_by(|a, b| a.name.cmp(&b.name));
+
+		assert_eq!(members.len(), 2);
+		assert_eq!(members[0].name, "crate-a");
+		assert_eq!(members[1].name, "crate-b");
+
+		std::fs::remove_dir_all(root).unwrap();
+	}
+
+	#[test]
+	fn test_workspace_members_dedupes_overlapping_globs() {
+		let root = temp_path("workspace-members-overlap");
+		std::fs::create_dir_all(root.join("crates/a")).unwrap();
+
+		std::fs::write(root.join("Cargo.toml"), "[workspace]\nmembers = [\"crates/*\", \"crates/a\"]\n").unwrap();
+		std::fs::write(root.join("crates/a/Cargo.toml"), "[package]\nname = \"crate-a\"\nversion = \"0.1.0\"\n").unwrap();
+
+		let root_text = std::fs::read_to_string(root.join("Cargo.toml")).unwrap();
+		let document = root_text.parse::<toml_edit::Document>().unwrap();
+
+		let members = workspace_members(root.to_str().unwrap(), &document).unwrap();
+
+		assert_eq!(members.len(), 1);
+		assert_eq!(members[0].name, "crate-a");
+
+		std::fs::remove_dir_all(root).unwrap();
+	}
+
 	#[test]
-	fn test_increment_build_number() {
-		let result = increment_build_number("0.0.0").unwrap_or_default();
+	fn test_update_internal_dependency_versions_only_touches_path_dependencies() {
+		let path = temp_path("internal-dependency-versions.toml");
+		std::fs::write(
+			&path,
+			concat!(
+				"[package]\n",
+				"name = \"crate-a\"\n",
+				"version = \"0.1.0\"\n",
+				"\n",
+				"[dependencies]\n",
+				"crate-b = { path = \"../crate-b\", version = \"0.2.0\" }\n",
+				"external-dep = \"1.2.3\"\n",
+			),
+		)
+		.unwrap();
+		let path_str = path.to_str().unwrap();
+
+		let mut bumped: std::collections::HashMap<String, String> = std::collections::HashMap::new();
+		bumped.insert("crate-b".to_string(), "0.3.0".to_string());
+		bumped.insert("external-dep".to_string(), "9.9.9".to_string());
+		update_internal_dependency_versions(path_str, &bumped).unwrap();
+
+		let text = std::fs::read_to_string(path_str).unwrap();
+		let document = text.parse::<toml_edit::Document>().unwrap();
+
+		assert_eq!(document["dependencies"]["crate-b"]["version"].as_str().unwrap(), "0.3.0");
+		assert_eq!(document["dependencies"]["external-dep"].as_str().unwrap(), "1.2.3");
+
+		std::fs::remove_file(path).unwrap();
+	}
+
+	#[test]
+	fn test_bump_version_patch() {
+		let result = bump_version("0.0.0", &Bump::Patch).unwrap_or_default();
 		assert_eq!(result, "0.0.1");
 
-		let result = increment_build_number("0.0.1").unwrap_or_default();
+		let result = bump_version("0.0.1", &Bump::Patch).unwrap_or_default();
 		assert_eq!(result, "0.0.2");
 
-		let result = increment_build_number("0.0.9").unwrap_or_default();
+		let result = bump_version("0.0.9", &Bump::Patch).unwrap_or_default();
 		assert_eq!(result, "0.0.10");
 
-		let result = increment_build_number("0.0.10").unwrap_or_default();
+		let result = bump_version("0.0.10", &Bump::Patch).unwrap_or_default();
 		assert_eq!(result, "0.0.11");
 
-		let result = increment_build_number("0.0.99").unwrap_or_default();
+		let result = bump_version("0.0.99", &Bump::Patch).unwrap_or_default();
 		assert_eq!(result, "0.0.100");
 
-		let result = increment_build_number("0.0.100").unwrap_or_default();
+		let result = bump_version("0.0.100", &Bump::Patch).unwrap_or_default();
 		assert_eq!(result, "0.0.101");
 
-		let result = increment_build_number("0.0.999").unwrap_or_default();
+		let result = bump_version("0.0.999", &Bump::Patch).unwrap_or_default();
 		assert_eq!(result, "0.0.1000");
 
-		let result = increment_build_number("0.0.1000").unwrap_or_default();
+		let result = bump_version("0.0.1000", &Bump::Patch).unwrap_or_default();
 		assert_eq!(result, "0.0.1001");
 
-		let result = increment_build_number("0.0.9999").unwrap_or_default();
+		let result = bump_version("0.0.9999", &Bump::Patch).unwrap_or_default();
 		assert_eq!(result, "0.0.10000");
 
-		let result = increment_build_number("0.0.10000").unwrap_or_default();
+		let result = bump_version("0.0.10000", &Bump::Patch).unwrap_or_default();
 		assert_eq!(result, "0.0.10001");
 
-		let result = increment_build_number("0.0.99999").unwrap_or_default();
+		let result = bump_version("0.0.99999", &Bump::Patch).unwrap_or_default();
 		assert_eq!(result, "0.0.100000");
 
-		let result = increment_build_number("0.0.100000").unwrap_or_default();
+		let result = bump_version("0.0.100000", &Bump::Patch).unwrap_or_default();
 		assert_eq!(result, "0.0.100001");
 
-		let result = increment_build_number("0.0.999999").unwrap_or_default();
+		let result = bump_version("0.0.999999", &Bump::Patch).unwrap_or_default();
 		assert_eq!(result, "0.0.1000000");
 
-		let result = increment_build_number("0.0.1000000").unwrap_or_default();
+		let result = bump_version("0.0.1000000", &Bump::Patch).unwrap_or_default();
 		assert_eq!(result, "0.0.1000001");
 
-		let result = increment_build_number("0.0.9999999").unwrap_or_default();
+		let result = bump_version("0.0.9999999", &Bump::Patch).unwrap_or_default();
 		assert_eq!(result, "0.0.10000000");
 
-		let result = increment_build_number("0.0.10000000").unwrap_or_default();
+		let result = bump_version("0.0.10000000", &Bump::Patch).unwrap_or_default();
 		assert_eq!(result, "0.0.10000001");
 	}
+
+	#[test]
+	fn test_bump_version_major() {
+		let result = bump_version("1.2.3", &Bump::Major).unwrap_or_default();
+		assert_eq!(result, "2.0.0");
+	}
+
+	#[test]
+	fn test_bump_version_minor() {
+		let result = bump_version("1.2.3", &Bump::Minor).unwrap_or_default();
+		assert_eq!(result, "1.3.0");
+	}
+
+	#[test]
+	fn test_bump_version_prerelease() {
+		let result = bump_version("1.2.3", &Bump::PreRelease).unwrap_or_default();
+		assert_eq!(result, "1.2.3-0");
+
+		let result = bump_version("1.2.3-rc.1", &Bump::PreRelease).unwrap_or_default();
+		assert_eq!(result, "1.2.3-rc.2");
+	}
+
+	#[test]
+	fn test_bump_version_custom() {
+		let result = bump_version("1.2.3", &Bump::Custom("9.9.9".to_string())).unwrap_or_default();
+		assert_eq!(result, "9.9.9");
+	}
+
+	#[test]
+	fn test_version_parse_roundtrip() {
+		let version = Version::parse("1.2.3").unwrap();
+		assert_eq!(version.to_string(), "1.2.3");
+
+		let version = Version::parse("0.1.0-alpha.3").unwrap();
+		assert_eq!(version.to_string(), "0.1.0-alpha.3");
+
+		let version = Version::parse("1.2.3+build.5").unwrap();
+		assert_eq!(version.to_string(), "1.2.3+build.5");
+
+		let version = Version::parse("1.2.3-rc.1+build.5").unwrap();
+		assert_eq!(version.to_string(), "1.2.3-rc.1+build.5");
+	}
+
+	#[test]
+	fn test_bump_version_drops_build_metadata() {
+		let result = bump_version("1.2.3+build.5", &Bump::Patch).unwrap_or_default();
+		assert_eq!(result, "1.2.4");
+	}
+
+	#[test]
+	fn test_requirement_parse_roundtrip() {
+		assert_eq!(Requirement::parse("^1.2.3").unwrap().to_string(), "^1.2.3");
+		assert_eq!(Requirement::parse("~1.2").unwrap().to_string(), "~1.2");
+		assert_eq!(Requirement::parse(">=1.0.0").unwrap().to_string(), ">=1.0.0");
+		assert_eq!(Requirement::parse("<=2.0.0").unwrap().to_string(), "<=2.0.0");
+		assert_eq!(Requirement::parse("=1.2.3").unwrap().to_string(), "=1.2.3");
+		assert_eq!(Requirement::parse("1.2").unwrap().to_string(), "1.2");
+		assert_eq!(Requirement::parse("*").unwrap().to_string(), "*");
+		assert_eq!(Requirement::parse("1.*").unwrap().to_string(), "1.*");
+	}
+
+	#[test]
+	fn test_requirement_parse_rejects_garbage() {
+		assert!(Requirement::parse("garbage-not-a-requirement").is_err());
+		assert!(Requirement::parse("^1.2.3.4").is_err());
+		assert!(Requirement::parse("").is_err());
+	}
+
+	#[test]
+	fn test_set_dependency_requirement_handles_bare_and_table_forms() {
+		let path = temp_path("set-dependency-requirement.toml");
+		std::fs::write(
+			&path,
+			concat!(
+				"[package]\n",
+				"name = \"demo\"\n",
+				"version = \"0.1.0\"\n",
+				"\n",
+				"[dependencies]\n",
+				"foo = \"1.0.0\"\n",
+				"\n",
+				"[dependencies.bar]\n",
+				"version = \"1.0.0\"\n",
+				"path = \"../bar\"\n",
+			),
+		)
+		.unwrap();
+		let path_str = path.to_str().unwrap();
+
+		set_dependency_requirement(path_str, "foo", "^2.0.0").unwrap();
+		set_dependency_requirement(path_str, "bar", "^2.0.0").unwrap();
+
+		let text = std::fs::read_to_string(path_str).unwrap();
+		let document = text.parse::<toml_edit::Document>().unwrap();
+		assert_eq!(document["dependencies"]["foo"].as_str().unwrap(), "^2.0.0");
+		assert_eq!(document["dependencies"]["bar"]["version"].as_str().unwrap(), "^2.0.0");
+		assert_eq!(document["dependencies"]["bar"]["path"].as_str().unwrap(), "../bar");
+
+		std::fs::remove_file(path).unwrap();
+	}
+
+	#[test]
+	fn test_set_dependency_requirement_rejects_garbage() {
+		let path = temp_path("set-dependency-requirement-garbage.toml");
+		std::fs::write(&path, "[dependencies]\nfoo = \"1.0.0\"\n").unwrap();
+		let path_str = path.to_str().unwrap();
+
+		assert!(set_dependency_requirement(path_str, "foo", "not-a-requirement").is_err());
+
+		// Rejected before any write, so the manifest is untouched.
+		let text = std::fs::read_to_string(path_str).unwrap();
+		assert_eq!(text, "[dependencies]\nfoo = \"1.0.0\"\n");
+
+		std::fs::remove_file(path).unwrap();
+	}
+
+	#[test]
+	fn test_emit_template_output_writes_clean_lines_to_file() {
+		let path = temp_path("emit-template-output.txt");
+		let path_str = path.to_str().unwrap().to_string();
+
+		emit_template_output(&Some(path_str.clone()), &["v1.2.3".to_string(), "crate-a: v2.0.0".to_string()]).unwrap();
+
+		let contents = std::fs::read_to_string(&path).unwrap();
+		assert_eq!(contents, "v1.2.3\ncrate-a: v2.0.0\n");
+
+		std::fs::remove_file(path).unwrap();
+	}
+
+	#[test]
+	fn test_template_render() {
+		let version = Version::parse("1.2.3-rc.1").unwrap();
+
+		let template = Template::parse("v{major}.{minor}.{patch}").unwrap();
+		assert_eq!(template.render(&version), "v1.2.3");
+
+		let template = Template::parse("#define VERSION \"{version}\"").unwrap();
+		assert_eq!(template.render(&version), "#define VERSION \"1.2.3-rc.1\"");
+
+		let template = Template::parse("pre={pre}").unwrap();
+		assert_eq!(template.render(&version), "pre=rc.1");
+	}
+
+	#[test]
+	fn test_template_parse_rejects_unterminated_placeholder() {
+		let result = Template::parse("v{major");
+		assert!(result.is_err());
+	}
 }